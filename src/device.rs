@@ -4,6 +4,8 @@ use std::sync::Arc;
 use std::{io, mem};
 
 use crate::control;
+use crate::event::Event;
+use crate::format::{Format, FourCC, FrameInterval, FrameSize};
 use crate::v4l2;
 use crate::v4l_sys::*;
 use crate::{capability::Capabilities, control::Control};
@@ -14,6 +16,60 @@ pub enum OpenFlags {
     Blocking = 1,
 }
 
+/// Re-issues a V4L2 ioctl transparently while it is interrupted by a signal.
+///
+/// A signal delivered to the calling thread makes `ioctl` fail with `EINTR` even
+/// though nothing went wrong; the correct response is to retry. Every V4L2 ioctl
+/// in this crate goes through this wrapper so callers never observe a spurious
+/// `EINTR`.
+#[macro_export]
+macro_rules! ioctl {
+    ($fd:expr, $request:expr, $argp:expr $(,)?) => {{
+        loop {
+            match $crate::v4l2::ioctl($fd, $request, $argp) {
+                Err(e) if e.raw_os_error() == Some(libc::EINTR) => continue,
+                other => break other,
+            }
+        }
+    }};
+}
+
+/// Operationally meaningful errors the V4L2 API reports through `errno`.
+///
+/// These distinguish the cases a caller is expected to react to differently —
+/// retry, renegotiate, or reopen — instead of string-matching an [`io::Error`].
+#[derive(Error, Debug)]
+pub enum Error {
+    /// `EINVAL` - the request, format or buffer type is not supported.
+    #[error("unsupported request or format (EINVAL)")]
+    Invalid,
+    /// `EBUSY` - the device is already streaming or owned by another user.
+    #[error("device busy (EBUSY)")]
+    Busy,
+    /// `ENODEV`/`ENXIO` - the device disappeared (e.g. unplugged mid-capture).
+    #[error("device disconnected (ENODEV/ENXIO)")]
+    Disconnected,
+    /// `EAGAIN` - no buffer is ready yet on a nonblocking fd.
+    #[error("no buffer ready (EAGAIN)")]
+    Again,
+    /// Any other I/O error.
+    #[error(transparent)]
+    Io(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        match e.raw_os_error() {
+            Some(libc::EINVAL) => Error::Invalid,
+            Some(libc::EBUSY) => Error::Busy,
+            Some(libc::ENODEV) | Some(libc::ENXIO) => Error::Disconnected,
+            Some(libc::EAGAIN) => Error::Again,
+            // preserve the original error (kind and message) for everything else
+            _ => Error::Io(e),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum WaitError {
     #[error("poll did not return an image before timeout")]
@@ -24,6 +80,40 @@ pub enum WaitError {
     DeviceError(i16),
 }
 
+/// Whether a control carries its value in a user-space payload buffer (the
+/// `ptr` union arm) rather than inline in the `value`/`value64` union arms.
+///
+/// This follows `V4L2_CTRL_FLAG_HAS_PAYLOAD` per control type: strings and the
+/// compound/array control types use a payload, every plain scalar type does not.
+/// Classifying by type (not buffer size) is what lets a short string or a
+/// single-element compound control be decoded correctly. An array of a plain
+/// scalar type (e.g. an `Integer` control with `elems > 1`) is not detected and
+/// is treated as an inline value.
+fn has_payload(typ: control::Type) -> bool {
+    !matches!(
+        typ,
+        control::Type::Integer
+            | control::Type::Boolean
+            | control::Type::Menu
+            | control::Type::IntegerMenu
+            | control::Type::Bitmask
+            | control::Type::Button
+            | control::Type::Integer64
+            | control::Type::CtrlClass
+    )
+}
+
+/// Parses the trailing integer of a `/dev/videoN` node path.
+///
+/// Nodes without a numeric suffix sort after the numbered ones.
+fn node_index(path: &Path) -> u32 {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.rsplit(|c: char| !c.is_ascii_digit()).next())
+        .and_then(|digits| digits.parse().ok())
+        .unwrap_or(u32::MAX)
+}
+
 /// Linux capture device abstraction
 pub struct Device {
     /// Raw handle
@@ -55,7 +145,10 @@ impl Device {
         }
 
         Ok(Device {
-            handle: Arc::new(Handle { fd }),
+            handle: Arc::new(Handle {
+                fd,
+                lock: std::sync::Mutex::new(()),
+            }),
         })
     }
 
@@ -81,7 +174,10 @@ impl Device {
         }
 
         Ok(Device {
-            handle: Arc::new(Handle { fd }),
+            handle: Arc::new(Handle {
+                fd,
+                lock: std::sync::Mutex::new(()),
+            }),
         })
     }
 
@@ -114,10 +210,53 @@ impl Device {
         }
 
         Ok(Device {
-            handle: Arc::new(Handle { fd }),
+            handle: Arc::new(Handle {
+                fd,
+                lock: std::sync::Mutex::new(()),
+            }),
         })
     }
 
+    /// Enumerates the video4linux capture nodes present on the system.
+    ///
+    /// Scans `/sys/class/video4linux`, opens each `/dev/videoN` node and queries its
+    /// capabilities. Nodes that cannot be opened or queried are skipped, so a caller
+    /// can pick, for instance, the first node advertising `V4L2_CAP_VIDEO_CAPTURE`
+    /// and ignore metadata, radio or output-only nodes of a multi-function device.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use v4l::device::Device;
+    /// for (path, caps) in Device::enumerate().unwrap() {
+    ///     println!("{:?}: {}", path, caps.card);
+    /// }
+    /// ```
+    pub fn enumerate() -> io::Result<Vec<(std::path::PathBuf, Capabilities)>> {
+        let mut devices = Vec::new();
+
+        for entry in std::fs::read_dir("/sys/class/video4linux")? {
+            let name = match entry {
+                Ok(entry) => entry.file_name(),
+                Err(_) => continue,
+            };
+            let path = Path::new("/dev").join(&name);
+
+            let device = match Device::with_path(&path) {
+                Ok(device) => device,
+                Err(_) => continue,
+            };
+            if let Ok(caps) = device.query_caps() {
+                devices.push((path, caps));
+            }
+        }
+
+        // present the nodes in a stable order regardless of readdir ordering;
+        // sort on the trailing node index so /dev/video2 precedes /dev/video10
+        devices.sort_by(|a, b| node_index(&a.0).cmp(&node_index(&b.0)).then(a.0.cmp(&b.0)));
+        Ok(devices)
+    }
+
     /// Returns the raw device handle
     pub fn handle(&self) -> Arc<Handle> {
         self.handle.clone()
@@ -127,8 +266,8 @@ impl Device {
     pub fn query_caps(&self) -> io::Result<Capabilities> {
         unsafe {
             let mut v4l2_caps: v4l2_capability = mem::zeroed();
-            v4l2::ioctl(
-                self.handle().fd(),
+            crate::ioctl!(
+                *self.handle().lock(),
                 v4l2::vidioc::VIDIOC_QUERYCAP,
                 &mut v4l2_caps as *mut _ as *mut std::os::raw::c_void,
             )?;
@@ -146,8 +285,8 @@ impl Device {
             loop {
                 v4l2_ctrl.id |= V4L2_CTRL_FLAG_NEXT_CTRL;
                 v4l2_ctrl.id |= V4L2_CTRL_FLAG_NEXT_COMPOUND;
-                match v4l2::ioctl(
-                    self.handle().fd(),
+                match crate::ioctl!(
+                    *self.handle().lock(),
                     v4l2::vidioc::VIDIOC_QUERYCTRL,
                     &mut v4l2_ctrl as *mut _ as *mut std::os::raw::c_void,
                 ) {
@@ -168,8 +307,8 @@ impl Device {
                                 .step_by(v4l2_ctrl.step as usize)
                             {
                                 v4l2_menu.index = i as u32;
-                                let res = v4l2::ioctl(
-                                    self.handle().fd(),
+                                let res = crate::ioctl!(
+                                    *self.handle().lock(),
                                     v4l2::vidioc::VIDIOC_QUERYMENU,
                                     &mut v4l2_menu as *mut _ as *mut std::os::raw::c_void,
                                 );
@@ -217,12 +356,12 @@ impl Device {
     /// # Arguments
     ///
     /// * `id` - Control identifier
-    pub fn control(&self, id: u32) -> io::Result<Control> {
+    pub fn control(&self, id: u32) -> Result<Control, Error> {
         unsafe {
             let mut v4l2_ctrl: v4l2_control = mem::zeroed();
             v4l2_ctrl.id = id;
-            v4l2::ioctl(
-                self.handle().fd(),
+            crate::ioctl!(
+                *self.handle().lock(),
                 v4l2::vidioc::VIDIOC_G_CTRL,
                 &mut v4l2_ctrl as *mut _ as *mut std::os::raw::c_void,
             )?;
@@ -237,24 +376,407 @@ impl Device {
     ///
     /// * `id` - Control identifier
     /// * `val` - New value
-    pub fn set_control(&self, id: u32, val: Control) -> io::Result<()> {
+    pub fn set_control(&self, id: u32, val: Control) -> Result<(), Error> {
         unsafe {
             let mut v4l2_ctrl: v4l2_control = mem::zeroed();
             v4l2_ctrl.id = id;
             match val {
                 Control::Value(val) => v4l2_ctrl.value = val,
                 _ => {
-                    return Err(io::Error::new(
+                    return Err(Error::Io(io::Error::new(
                         io::ErrorKind::InvalidInput,
                         "only single value controls are supported at the moment",
-                    ))
+                    )))
                 }
             }
-            v4l2::ioctl(
-                self.handle().fd(),
+            crate::ioctl!(
+                *self.handle().lock(),
                 v4l2::vidioc::VIDIOC_S_CTRL,
                 &mut v4l2_ctrl as *mut _ as *mut std::os::raw::c_void,
+            )?;
+            Ok(())
+        }
+    }
+
+    /// Enumerates the pixel formats supported on the capture buffer type.
+    pub fn enum_formats(&self) -> io::Result<Vec<FourCC>> {
+        let mut formats = Vec::new();
+        unsafe {
+            let mut v4l2_fmt: v4l2_fmtdesc = mem::zeroed();
+            v4l2_fmt.type_ = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+            loop {
+                match crate::ioctl!(
+                    *self.handle().lock(),
+                    v4l2::vidioc::VIDIOC_ENUM_FMT,
+                    &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
+                ) {
+                    Ok(_) => {
+                        formats.push(FourCC::from(v4l2_fmt.pixelformat));
+                        v4l2_fmt.index += 1;
+                    }
+                    Err(e) => {
+                        if e.kind() == io::ErrorKind::InvalidInput {
+                            break;
+                        } else {
+                            return Err(e);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(formats)
+    }
+
+    /// Enumerates the frame sizes a driver supports for a pixel format.
+    ///
+    /// # Arguments
+    ///
+    /// * `fourcc` - Pixel format to query
+    pub fn enum_framesizes(&self, fourcc: FourCC) -> io::Result<Vec<FrameSize>> {
+        let mut sizes = Vec::new();
+        unsafe {
+            let mut v4l2_size: v4l2_frmsizeenum = mem::zeroed();
+            v4l2_size.pixel_format = fourcc.code();
+            loop {
+                match crate::ioctl!(
+                    *self.handle().lock(),
+                    v4l2::vidioc::VIDIOC_ENUM_FRAMESIZES,
+                    &mut v4l2_size as *mut _ as *mut std::os::raw::c_void,
+                ) {
+                    Ok(_) => {
+                        if v4l2_size.type_ == V4L2_FRMSIZE_TYPE_DISCRETE {
+                            sizes.push(FrameSize::Discrete {
+                                width: v4l2_size.__bindgen_anon_1.discrete.width,
+                                height: v4l2_size.__bindgen_anon_1.discrete.height,
+                            });
+                        } else {
+                            let s = v4l2_size.__bindgen_anon_1.stepwise;
+                            sizes.push(FrameSize::Stepwise {
+                                min_width: s.min_width,
+                                max_width: s.max_width,
+                                step_width: s.step_width,
+                                min_height: s.min_height,
+                                max_height: s.max_height,
+                                step_height: s.step_height,
+                            });
+                            // stepwise/continuous enumeration terminates after one entry
+                            break;
+                        }
+                        v4l2_size.index += 1;
+                    }
+                    Err(e) => {
+                        if e.kind() == io::ErrorKind::InvalidInput {
+                            break;
+                        } else {
+                            return Err(e);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(sizes)
+    }
+
+    /// Enumerates the frame intervals (inverse frame rates) for a format and size.
+    ///
+    /// # Arguments
+    ///
+    /// * `fourcc` - Pixel format to query
+    /// * `width` - Frame width in pixels
+    /// * `height` - Frame height in pixels
+    pub fn enum_frameintervals(
+        &self,
+        fourcc: FourCC,
+        width: u32,
+        height: u32,
+    ) -> io::Result<Vec<FrameInterval>> {
+        let mut intervals = Vec::new();
+        unsafe {
+            let mut v4l2_ival: v4l2_frmivalenum = mem::zeroed();
+            v4l2_ival.pixel_format = fourcc.code();
+            v4l2_ival.width = width;
+            v4l2_ival.height = height;
+            loop {
+                match crate::ioctl!(
+                    *self.handle().lock(),
+                    v4l2::vidioc::VIDIOC_ENUM_FRAMEINTERVALS,
+                    &mut v4l2_ival as *mut _ as *mut std::os::raw::c_void,
+                ) {
+                    Ok(_) => {
+                        if v4l2_ival.type_ == V4L2_FRMIVAL_TYPE_DISCRETE {
+                            let d = v4l2_ival.__bindgen_anon_1.discrete;
+                            intervals.push(FrameInterval::Discrete {
+                                numerator: d.numerator,
+                                denominator: d.denominator,
+                            });
+                        } else {
+                            let s = v4l2_ival.__bindgen_anon_1.stepwise;
+                            intervals.push(FrameInterval::Stepwise {
+                                min: (s.min.numerator, s.min.denominator),
+                                max: (s.max.numerator, s.max.denominator),
+                                step: (s.step.numerator, s.step.denominator),
+                            });
+                            break;
+                        }
+                        v4l2_ival.index += 1;
+                    }
+                    Err(e) => {
+                        if e.kind() == io::ErrorKind::InvalidInput {
+                            break;
+                        } else {
+                            return Err(e);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(intervals)
+    }
+
+    /// Returns the pixel format currently configured on the capture buffer type.
+    pub fn format(&self) -> io::Result<Format> {
+        unsafe {
+            let mut v4l2_fmt: v4l2_format = mem::zeroed();
+            v4l2_fmt.type_ = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+            crate::ioctl!(
+                *self.handle().lock(),
+                v4l2::vidioc::VIDIOC_G_FMT,
+                &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            Ok(Format::from(v4l2_fmt.fmt.pix))
+        }
+    }
+
+    /// Requests a pixel format and returns the one the driver actually accepted.
+    ///
+    /// The driver may clamp the geometry or substitute a supported pixel format,
+    /// so the returned `Format` must be used when sizing streaming buffers.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Desired capture format
+    pub fn set_format(&self, format: &Format) -> io::Result<Format> {
+        unsafe {
+            let mut v4l2_fmt: v4l2_format = mem::zeroed();
+            v4l2_fmt.type_ = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+            v4l2_fmt.fmt.pix.width = format.width;
+            v4l2_fmt.fmt.pix.height = format.height;
+            v4l2_fmt.fmt.pix.pixelformat = format.fourcc.code();
+            v4l2_fmt.fmt.pix.field = V4L2_FIELD_ANY;
+            crate::ioctl!(
+                *self.handle().lock(),
+                v4l2::vidioc::VIDIOC_S_FMT,
+                &mut v4l2_fmt as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            Ok(Format::from(v4l2_fmt.fmt.pix))
+        }
+    }
+
+    /// Reads one or more controls using the extended-control ioctl.
+    ///
+    /// Unlike [`control`](Self::control), this uses `VIDIOC_G_EXT_CTRLS`, so 64-bit
+    /// integers and payload controls (strings, arrays, compound controls) are read
+    /// correctly. Payload buffers are sized from each control's `elem_size`/`elems`.
+    /// The batch is read against `V4L2_CTRL_WHICH_CUR_VAL`.
+    ///
+    /// # Arguments
+    ///
+    /// * `descriptions` - Descriptions of the controls to read
+    pub fn ext_controls(
+        &self,
+        descriptions: &[control::Description],
+    ) -> Result<Vec<Control>, Error> {
+        unsafe {
+            // payload controls need a user-owned buffer the driver writes into
+            let mut payloads: Vec<Vec<u8>> = Vec::with_capacity(descriptions.len());
+            let mut ctrls: Vec<v4l2_ext_control> = Vec::with_capacity(descriptions.len());
+
+            for desc in descriptions {
+                let mut ctrl: v4l2_ext_control = mem::zeroed();
+                ctrl.id = desc.id;
+                if has_payload(desc.typ) {
+                    let size = desc.elem_size * desc.elems;
+                    ctrl.size = size;
+                    payloads.push(vec![0u8; size as usize]);
+                    ctrl.__bindgen_anon_1.ptr =
+                        payloads.last_mut().unwrap().as_mut_ptr() as *mut std::os::raw::c_void;
+                } else {
+                    payloads.push(Vec::new());
+                }
+                ctrls.push(ctrl);
+            }
+
+            let mut ext: v4l2_ext_controls = mem::zeroed();
+            ext.which = V4L2_CTRL_WHICH_CUR_VAL;
+            ext.count = ctrls.len() as u32;
+            ext.controls = ctrls.as_mut_ptr();
+            crate::ioctl!(
+                *self.handle().lock(),
+                v4l2::vidioc::VIDIOC_G_EXT_CTRLS,
+                &mut ext as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            Ok(descriptions
+                .iter()
+                .zip(ctrls.iter())
+                .zip(payloads.iter())
+                .map(|((desc, ctrl), payload)| match desc.typ {
+                    // the driver wrote into our buffer, not the union
+                    control::Type::String => {
+                        let bytes = &payload[..ctrl.size as usize];
+                        Control::String(
+                            String::from_utf8_lossy(bytes)
+                                .trim_end_matches('\0')
+                                .to_string(),
+                        )
+                    }
+                    // integer/compound arrays keep their raw little-endian bytes so
+                    // the caller can decode the element type without corruption
+                    typ if has_payload(typ) => {
+                        Control::Payload(payload[..ctrl.size as usize].to_vec())
+                    }
+                    control::Type::Integer64 => Control::Value64(ctrl.__bindgen_anon_1.value64),
+                    _ => Control::Value(ctrl.__bindgen_anon_1.value),
+                })
+                .collect())
+        }
+    }
+
+    /// Writes one or more controls atomically using the extended-control ioctl.
+    ///
+    /// All controls in the batch are applied together via `VIDIOC_S_EXT_CTRLS`
+    /// against `V4L2_CTRL_WHICH_CUR_VAL`; if the driver rejects any of them the
+    /// whole batch fails and none are applied. This is the only correct way to set
+    /// interdependent controls such as exposure-mode plus absolute-exposure.
+    ///
+    /// # Arguments
+    ///
+    /// * `controls` - `(id, value)` pairs to apply in one transaction
+    pub fn set_ext_controls(&self, controls: &[(u32, Control)]) -> Result<(), Error> {
+        unsafe {
+            // payload controls need a user-owned buffer that outlives the ioctl
+            let mut payloads: Vec<Vec<u8>> = Vec::with_capacity(controls.len());
+            let mut ctrls: Vec<v4l2_ext_control> = Vec::with_capacity(controls.len());
+            for (id, val) in controls {
+                let mut ctrl: v4l2_ext_control = mem::zeroed();
+                ctrl.id = *id;
+                match val {
+                    Control::Value(val) => {
+                        ctrl.__bindgen_anon_1.value = *val;
+                        payloads.push(Vec::new());
+                    }
+                    Control::Value64(val) => {
+                        ctrl.__bindgen_anon_1.value64 = *val;
+                        payloads.push(Vec::new());
+                    }
+                    Control::String(val) => {
+                        // string controls expect a NUL-terminated payload buffer
+                        let mut bytes = val.clone().into_bytes();
+                        bytes.push(0);
+                        ctrl.size = bytes.len() as u32;
+                        payloads.push(bytes);
+                        ctrl.__bindgen_anon_1.ptr = payloads.last_mut().unwrap().as_mut_ptr()
+                            as *mut std::os::raw::c_void;
+                    }
+                    Control::Payload(bytes) => {
+                        // integer/compound array controls pass their raw bytes through
+                        ctrl.size = bytes.len() as u32;
+                        payloads.push(bytes.clone());
+                        ctrl.__bindgen_anon_1.ptr = payloads.last_mut().unwrap().as_mut_ptr()
+                            as *mut std::os::raw::c_void;
+                    }
+                }
+                ctrls.push(ctrl);
+            }
+
+            let mut ext: v4l2_ext_controls = mem::zeroed();
+            ext.which = V4L2_CTRL_WHICH_CUR_VAL;
+            ext.count = ctrls.len() as u32;
+            ext.controls = ctrls.as_mut_ptr();
+            crate::ioctl!(
+                *self.handle().lock(),
+                v4l2::vidioc::VIDIOC_S_EXT_CTRLS,
+                &mut ext as *mut _ as *mut std::os::raw::c_void,
             )
+            .map_err(|e| {
+                // the driver applies the batch atomically and flags the rejected
+                // control via error_idx; it is added to the message of errors that
+                // map to Error::Io, the classified variants do not carry it
+                let idx = ext.error_idx;
+                match Error::from(e) {
+                    Error::Io(io) => Error::Io(io::Error::new(
+                        io.kind(),
+                        format!("S_EXT_CTRLS rejected control at index {}: {}", idx, io),
+                    )),
+                    classified => classified,
+                }
+            })?;
+            Ok(())
+        }
+    }
+
+    /// Subscribes to an event type so the driver starts delivering it.
+    ///
+    /// Subscribed events are signalled by `POLLPRI` in [`wait`](Self::wait) and
+    /// retrieved with [`dequeue_event`](Self::dequeue_event).
+    ///
+    /// # Arguments
+    ///
+    /// * `typ` - Event type (e.g. `V4L2_EVENT_CTRL`, `V4L2_EVENT_SOURCE_CHANGE`)
+    /// * `id` - Object the event applies to (e.g. the control id for `V4L2_EVENT_CTRL`)
+    pub fn subscribe_event(&self, typ: u32, id: u32) -> io::Result<()> {
+        unsafe {
+            let mut sub: v4l2_event_subscription = mem::zeroed();
+            sub.type_ = typ;
+            sub.id = id;
+            crate::ioctl!(
+                *self.handle().lock(),
+                v4l2::vidioc::VIDIOC_SUBSCRIBE_EVENT,
+                &mut sub as *mut _ as *mut std::os::raw::c_void,
+            )
+        }
+    }
+
+    /// Unsubscribes from an event type previously registered with
+    /// [`subscribe_event`](Self::subscribe_event).
+    ///
+    /// # Arguments
+    ///
+    /// * `typ` - Event type to stop receiving
+    /// * `id` - Object the subscription applied to
+    pub fn unsubscribe_event(&self, typ: u32, id: u32) -> io::Result<()> {
+        unsafe {
+            let mut sub: v4l2_event_subscription = mem::zeroed();
+            sub.type_ = typ;
+            sub.id = id;
+            crate::ioctl!(
+                *self.handle().lock(),
+                v4l2::vidioc::VIDIOC_UNSUBSCRIBE_EVENT,
+                &mut sub as *mut _ as *mut std::os::raw::c_void,
+            )
+        }
+    }
+
+    /// Dequeues a pending event.
+    ///
+    /// Call this after [`wait`](Self::wait) reports `POLLPRI`. The returned [`Event`]
+    /// carries the changed control id or the new frame geometry so a streaming client
+    /// can renegotiate its format without tearing everything down.
+    pub fn dequeue_event(&self) -> io::Result<Event> {
+        unsafe {
+            let mut ev: v4l2_event = mem::zeroed();
+            crate::ioctl!(
+                *self.handle().lock(),
+                v4l2::vidioc::VIDIOC_DQEVENT,
+                &mut ev as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            Ok(Event::from(ev))
         }
     }
 
@@ -291,7 +813,7 @@ impl io::Read for Device {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         unsafe {
             let ret = libc::read(
-                self.handle().fd(),
+                *self.handle().lock(),
                 buf.as_mut_ptr() as *mut std::os::raw::c_void,
                 buf.len(),
             );
@@ -307,7 +829,7 @@ impl io::Write for Device {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         unsafe {
             let ret = libc::write(
-                self.handle().fd(),
+                *self.handle().lock(),
                 buf.as_ptr() as *const std::os::raw::c_void,
                 buf.len(),
             );
@@ -329,8 +851,15 @@ impl io::Write for Device {
 /// Device handle for low-level access.
 ///
 /// Acquiring a handle facilitates (possibly mutating) interactions with the device.
+///
+/// A [`Device::handle`] hands out `Arc<Handle>` clones that may be shared across
+/// threads — e.g. a capture loop on one thread while another adjusts controls.
+/// V4L2 ioctls on a single fd are not all safe to interleave (a `DQBUF` racing a
+/// `S_CTRL` or a `REQBUFS` can corrupt the in-flight `v4l2_buffer`/`v4l2_control`
+/// structs), so access is serialized through [`Handle::lock`].
 pub struct Handle {
     fd: std::os::raw::c_int,
+    lock: std::sync::Mutex<()>,
 }
 
 impl Handle {
@@ -338,6 +867,34 @@ impl Handle {
     pub fn fd(&self) -> std::os::raw::c_int {
         self.fd
     }
+
+    /// Locks the handle for exclusive ioctl/read/write access.
+    ///
+    /// The returned guard dereferences to the raw file descriptor and releases the
+    /// lock when dropped. Hold it for the duration of a dependent sequence such as a
+    /// `QBUF`/`DQBUF` pair so no other thread can interleave an ioctl in between.
+    pub fn lock(&self) -> HandleGuard<'_> {
+        HandleGuard {
+            fd: self.fd,
+            _guard: self.lock.lock().unwrap(),
+        }
+    }
+}
+
+/// Exclusive-access guard over a [`Handle`]'s file descriptor.
+///
+/// Dereferences to the raw fd; the lock is held until the guard is dropped.
+pub struct HandleGuard<'a> {
+    fd: std::os::raw::c_int,
+    _guard: std::sync::MutexGuard<'a, ()>,
+}
+
+impl std::ops::Deref for HandleGuard<'_> {
+    type Target = std::os::raw::c_int;
+
+    fn deref(&self) -> &std::os::raw::c_int {
+        &self.fd
+    }
 }
 
 impl Drop for Handle {
@@ -0,0 +1,45 @@
+use crate::v4l_sys::*;
+
+/// A dequeued video4linux event.
+///
+/// Returned by [`Device::dequeue_event`](crate::device::Device::dequeue_event)
+/// after [`wait`](crate::device::Device::wait) reports `POLLPRI`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// A control changed value or range out-of-band (`V4L2_EVENT_CTRL`).
+    ///
+    /// Common when auto-exposure adjusts gain/exposure behind the application's
+    /// back. Carries the affected control id and the bitmask of what changed.
+    Ctrl { id: u32, changes: u32 },
+    /// The source geometry or format changed (`V4L2_EVENT_SOURCE_CHANGE`).
+    ///
+    /// Critical for HDMI/SDI capture: the client should renegotiate the format.
+    SourceChange { changes: u32 },
+    /// End of stream reached (`V4L2_EVENT_EOS`).
+    Eos,
+    /// A frame was captured (`V4L2_EVENT_FRAME_SYNC`), carrying its sequence.
+    FrameSync { frame_sequence: u32 },
+    /// Any other event, carrying its raw type.
+    Other { typ: u32 },
+}
+
+impl From<v4l2_event> for Event {
+    fn from(ev: v4l2_event) -> Self {
+        unsafe {
+            match ev.type_ {
+                V4L2_EVENT_CTRL => Event::Ctrl {
+                    id: ev.id,
+                    changes: ev.u.ctrl.changes,
+                },
+                V4L2_EVENT_SOURCE_CHANGE => Event::SourceChange {
+                    changes: ev.u.src_change.changes,
+                },
+                V4L2_EVENT_EOS => Event::Eos,
+                V4L2_EVENT_FRAME_SYNC => Event::FrameSync {
+                    frame_sequence: ev.u.frame_sync.frame_sequence,
+                },
+                typ => Event::Other { typ },
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+
+use crate::v4l_sys::*;
+
+/// A four-character code identifying a pixel format (e.g. `YUYV`, `MJPG`).
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct FourCC {
+    pub repr: [u8; 4],
+}
+
+impl FourCC {
+    /// Returns a four character code from its byte representation.
+    pub fn new(repr: &[u8; 4]) -> Self {
+        FourCC { repr: *repr }
+    }
+
+    /// Returns the packed 32-bit representation used by the kernel.
+    pub fn code(self) -> u32 {
+        (self.repr[0] as u32)
+            | ((self.repr[1] as u32) << 8)
+            | ((self.repr[2] as u32) << 16)
+            | ((self.repr[3] as u32) << 24)
+    }
+}
+
+impl From<u32> for FourCC {
+    fn from(code: u32) -> Self {
+        FourCC {
+            repr: [
+                (code & 0xff) as u8,
+                ((code >> 8) & 0xff) as u8,
+                ((code >> 16) & 0xff) as u8,
+                ((code >> 24) & 0xff) as u8,
+            ],
+        }
+    }
+}
+
+impl fmt::Display for FourCC {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(&self.repr))
+    }
+}
+
+impl fmt::Debug for FourCC {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FourCC({})", self)
+    }
+}
+
+/// A capture pixel format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Format {
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// Pixel format as a four character code
+    pub fourcc: FourCC,
+    /// Bytes per line (stride)
+    pub bytesperline: u32,
+    /// Size of the whole image in bytes
+    pub sizeimage: u32,
+}
+
+impl Format {
+    /// Returns a format with the given geometry and pixel format.
+    ///
+    /// `bytesperline` and `sizeimage` are left at zero for the driver to fill in.
+    pub fn new(width: u32, height: u32, fourcc: FourCC) -> Self {
+        Format {
+            width,
+            height,
+            fourcc,
+            bytesperline: 0,
+            sizeimage: 0,
+        }
+    }
+}
+
+impl From<v4l2_pix_format> for Format {
+    fn from(pix: v4l2_pix_format) -> Self {
+        Format {
+            width: pix.width,
+            height: pix.height,
+            fourcc: FourCC::from(pix.pixelformat),
+            bytesperline: pix.bytesperline,
+            sizeimage: pix.sizeimage,
+        }
+    }
+}
+
+/// A supported frame size for a given pixel format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FrameSize {
+    /// A single discrete resolution
+    Discrete { width: u32, height: u32 },
+    /// A range of resolutions with a step between the bounds
+    Stepwise {
+        min_width: u32,
+        max_width: u32,
+        step_width: u32,
+        min_height: u32,
+        max_height: u32,
+        step_height: u32,
+    },
+}
+
+/// A supported frame interval (inverse of frame rate) for a given size.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FrameInterval {
+    /// A single discrete interval, expressed as a `numerator/denominator` fraction
+    Discrete { numerator: u32, denominator: u32 },
+    /// A range of intervals with a step between the bounds
+    Stepwise {
+        min: (u32, u32),
+        max: (u32, u32),
+        step: (u32, u32),
+    },
+}
@@ -0,0 +1,288 @@
+use std::sync::Arc;
+use std::{io, mem, slice};
+
+use crate::device::{Error, Handle};
+use crate::v4l2;
+use crate::v4l_sys::*;
+
+/// A single memory-mapped buffer from the driver's buffer pool.
+struct MappedBuffer {
+    start: *mut std::os::raw::c_void,
+    length: usize,
+}
+
+impl MappedBuffer {
+    /// Maps the buffer identified by `index` into user space.
+    ///
+    /// The buffer must already have been allocated via `VIDIOC_REQBUFS`.
+    unsafe fn map(handle: &Handle, index: u32) -> io::Result<Self> {
+        let mut v4l2_buf: v4l2_buffer = mem::zeroed();
+        v4l2_buf.type_ = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+        v4l2_buf.memory = V4L2_MEMORY_MMAP;
+        v4l2_buf.index = index;
+        crate::ioctl!(
+            *handle.lock(),
+            v4l2::vidioc::VIDIOC_QUERYBUF,
+            &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
+        )?;
+
+        let length = v4l2_buf.length as usize;
+        let start = libc::mmap(
+            std::ptr::null_mut(),
+            length,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_SHARED,
+            handle.fd(),
+            v4l2_buf.m.offset as libc::off_t,
+        );
+        if start == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(MappedBuffer { start, length })
+    }
+}
+
+impl Drop for MappedBuffer {
+    fn drop(&mut self) {
+        // Safe to unmap: the stream has already issued STREAMOFF before the
+        // buffers are dropped, so the driver no longer references this region.
+        unsafe {
+            libc::munmap(self.start, self.length);
+        }
+    }
+}
+
+/// Memory-mapped streaming capture on top of a shared device [`Handle`].
+///
+/// A `Stream` allocates a driver-side buffer pool via `VIDIOC_REQBUFS`, maps each
+/// buffer into user space, and starts streaming. [`Stream::next`] blocks until a
+/// filled buffer is ready and hands back a [`Frame`] borrowing the mapped slice.
+/// Dropping the frame re-queues its buffer; dropping the stream stops streaming,
+/// unmaps every region and frees the pool.
+pub struct Stream {
+    handle: Arc<Handle>,
+    buffers: Vec<MappedBuffer>,
+    /// Number of buffers currently handed out to the driver (queued).
+    queued: usize,
+    active: bool,
+}
+
+impl Stream {
+    /// Sets up a streaming capture with `count` memory-mapped buffers.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - Shared device handle
+    /// * `count` - Number of buffers to request from the driver
+    pub fn new(handle: Arc<Handle>, count: u32) -> Result<Self, Error> {
+        unsafe {
+            let mut v4l2_reqbufs: v4l2_requestbuffers = mem::zeroed();
+            v4l2_reqbufs.count = count;
+            v4l2_reqbufs.type_ = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+            v4l2_reqbufs.memory = V4L2_MEMORY_MMAP;
+            crate::ioctl!(
+                *handle.lock(),
+                v4l2::vidioc::VIDIOC_REQBUFS,
+                &mut v4l2_reqbufs as *mut _ as *mut std::os::raw::c_void,
+            )?;
+
+            // Construct the stream before mapping so that any failure below tears
+            // the pool back down via `Stream::drop` (REQBUFS(0) + munmap) instead of
+            // leaking the driver-side pool and the regions already mapped.
+            let mut stream = Stream {
+                handle,
+                buffers: Vec::with_capacity(v4l2_reqbufs.count as usize),
+                queued: 0,
+                active: false,
+            };
+
+            for index in 0..v4l2_reqbufs.count {
+                let buffer = MappedBuffer::map(&stream.handle, index)?;
+                stream.buffers.push(buffer);
+            }
+
+            // enqueue every buffer so the driver can start filling them
+            for index in 0..stream.buffers.len() {
+                stream.queue(index as u32)?;
+            }
+            stream.start()?;
+
+            Ok(stream)
+        }
+    }
+
+    /// Dequeues the next filled buffer, blocking until one is ready.
+    ///
+    /// Returns a [`Frame`] that borrows the mapped slice and re-queues the buffer
+    /// when dropped.
+    pub fn next(&mut self) -> Result<Frame<'_>, Error> {
+        // never dequeue more buffers than we handed to the driver
+        if self.queued == 0 {
+            return Err(Error::Io(io::Error::new(
+                io::ErrorKind::WouldBlock,
+                "no buffers queued to the driver",
+            )));
+        }
+
+        // block until the fd signals a filled buffer is ready (POLLIN)
+        let mut pollfd = libc::pollfd {
+            fd: self.handle.fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        if unsafe { libc::poll(&mut pollfd, 1, -1) } == -1 {
+            return Err(Error::from(io::Error::last_os_error()));
+        }
+
+        // POLLERR/POLLHUP mean the device went away (e.g. unplugged mid-stream);
+        // don't fall through to DQBUF on a fd that can no longer deliver frames.
+        if pollfd.revents & (libc::POLLERR | libc::POLLHUP | libc::POLLNVAL) != 0 {
+            return Err(Error::Disconnected);
+        }
+        if pollfd.revents & libc::POLLIN == 0 {
+            return Err(Error::Again);
+        }
+
+        unsafe {
+            let mut v4l2_buf: v4l2_buffer = mem::zeroed();
+            v4l2_buf.type_ = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+            v4l2_buf.memory = V4L2_MEMORY_MMAP;
+            crate::ioctl!(
+                *self.handle.lock(),
+                v4l2::vidioc::VIDIOC_DQBUF,
+                &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
+            )?;
+            self.queued -= 1;
+
+            let buffer = &self.buffers[v4l2_buf.index as usize];
+            let data = slice::from_raw_parts(
+                buffer.start as *const u8,
+                v4l2_buf.bytesused as usize,
+            );
+
+            Ok(Frame {
+                stream: self as *mut Stream,
+                index: v4l2_buf.index,
+                data,
+                metadata: v4l2_buf,
+            })
+        }
+    }
+
+    /// Enqueues the buffer at `index` for the driver to fill.
+    fn queue(&mut self, index: u32) -> io::Result<()> {
+        unsafe {
+            let mut v4l2_buf: v4l2_buffer = mem::zeroed();
+            v4l2_buf.type_ = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+            v4l2_buf.memory = V4L2_MEMORY_MMAP;
+            v4l2_buf.index = index;
+            crate::ioctl!(
+                *self.handle.lock(),
+                v4l2::vidioc::VIDIOC_QBUF,
+                &mut v4l2_buf as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+        self.queued += 1;
+        Ok(())
+    }
+
+    /// Issues `VIDIOC_STREAMON`.
+    fn start(&mut self) -> io::Result<()> {
+        unsafe {
+            let mut typ: v4l2_buf_type = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+            crate::ioctl!(
+                *self.handle.lock(),
+                v4l2::vidioc::VIDIOC_STREAMON,
+                &mut typ as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+        self.active = true;
+        Ok(())
+    }
+
+    /// Issues `VIDIOC_STREAMOFF`.
+    fn stop(&mut self) -> io::Result<()> {
+        unsafe {
+            let mut typ: v4l2_buf_type = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+            crate::ioctl!(
+                *self.handle.lock(),
+                v4l2::vidioc::VIDIOC_STREAMOFF,
+                &mut typ as *mut _ as *mut std::os::raw::c_void,
+            )?;
+        }
+        self.active = false;
+        Ok(())
+    }
+}
+
+impl Drop for Stream {
+    fn drop(&mut self) {
+        // Stop streaming before unmapping: the driver must not hold references to
+        // the buffers while we tear their mappings down.
+        if self.active {
+            let _ = self.stop();
+        }
+
+        // Unmap every region first (each `MappedBuffer::drop` munmaps), then free
+        // the driver pool: never munmap while STREAMON is active, handled above.
+        self.buffers.clear();
+
+        unsafe {
+            let mut v4l2_reqbufs: v4l2_requestbuffers = mem::zeroed();
+            v4l2_reqbufs.count = 0;
+            v4l2_reqbufs.type_ = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+            v4l2_reqbufs.memory = V4L2_MEMORY_MMAP;
+            let _ = crate::ioctl!(
+                *self.handle.lock(),
+                v4l2::vidioc::VIDIOC_REQBUFS,
+                &mut v4l2_reqbufs as *mut _ as *mut std::os::raw::c_void,
+            );
+        }
+    }
+}
+
+/// A captured frame borrowing its stream.
+///
+/// Dereferences to the raw frame bytes. The underlying buffer is automatically
+/// re-queued with the driver when the frame is dropped.
+pub struct Frame<'a> {
+    stream: *mut Stream,
+    index: u32,
+    data: &'a [u8],
+    metadata: v4l2_buffer,
+}
+
+impl<'a> Frame<'a> {
+    /// Number of bytes the driver wrote into the buffer.
+    pub fn bytesused(&self) -> u32 {
+        self.metadata.bytesused
+    }
+
+    /// Sequence number of the captured frame.
+    pub fn sequence(&self) -> u32 {
+        self.metadata.sequence
+    }
+
+    /// Timestamp of the captured frame.
+    pub fn timestamp(&self) -> timeval {
+        self.metadata.timestamp
+    }
+}
+
+impl<'a> std::ops::Deref for Frame<'a> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.data
+    }
+}
+
+impl<'a> Drop for Frame<'a> {
+    fn drop(&mut self) {
+        // Re-queue the buffer so the driver can reuse it for a later capture.
+        unsafe {
+            let _ = (*self.stream).queue(self.index);
+        }
+    }
+}